@@ -1,13 +1,16 @@
 //! Artifacts CLI - Main entry point
 //!
 //! This binary provides the `artifacts` command, an interactive TUI for
-//! managing NixOS artifacts. The TUI is the only mode; there are no
-//! subcommands.
+//! managing NixOS artifacts, plus headless subcommands for scripting.
 //!
 //! ## Usage
 //!
 //! - `artifacts` — Launch the TUI against the flake in the current directory
 //! - `artifacts /path/to/flake` — Launch the TUI against a different flake
+//! - `artifacts generate [flake]` — Check and generate every missing artifact
+//!   without the TUI, printing line-oriented progress
+//! - `artifacts status [flake]` — Run only the check scripts and report which
+//!   artifacts are present (`--format json` for machine-readable output)
 //!
 //! ## Configuration
 //!
@@ -21,7 +24,9 @@
 //! - **0** - The TUI ran to completion. Per-artifact failures are reported on
 //!   stderr but do not change the exit code.
 //! - **1** - The TUI itself failed (configuration load, terminal init, runtime
-//!   error).
+//!   error), or `artifacts generate` left an artifact failed or ungenerated.
+//! - **3** - `artifacts status`: at least one artifact needs generation.
+//! - **4** - `artifacts status`: at least one check failed.
 //!
 //! ## Logging
 //!
@@ -33,10 +38,13 @@
 /// Initializes the CLI, parses arguments, and runs the appropriate command.
 /// On error, prints the error to stderr and exits with code 1.
 #[tokio::main]
-async fn main() {
-    if let Err(err) = artifacts::cli::run().await {
-        artifacts::log_error!("{:#}", err);
-        eprintln!("{:#}", err);
-        std::process::exit(1);
+async fn main() -> std::process::ExitCode {
+    match artifacts::cli::run().await {
+        Ok(code) => code,
+        Err(err) => {
+            artifacts::log_error!("{:#}", err);
+            eprintln!("{:#}", err);
+            std::process::ExitCode::FAILURE
+        }
     }
 }
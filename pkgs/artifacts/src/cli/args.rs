@@ -12,9 +12,18 @@
 //!
 //! # Specify flake directory
 //! artifacts /path/to/flake
+//!
+//! # Check and generate every missing artifact without the TUI
+//! artifacts generate /path/to/flake
+//!
+//! # Report which artifacts are missing, as JSON
+//! artifacts status --format json
+//!
+//! # Export the artifact inventory as a Markdown table
+//! artifacts list --format markdown
 //! ```
 
-use clap::{Parser, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 /// Logging level for debug output.
@@ -45,12 +54,16 @@ pub struct Cli {
     pub flake: Option<PathBuf>,
 
     /// Path to log file for debug output
-    #[arg(long = "log-file", value_name = "PATH")]
+    #[arg(long = "log-file", value_name = "PATH", global = true)]
     pub log_file: Option<PathBuf>,
 
     /// Set the logging level (requires --log-file)
-    #[arg(long = "log-level", value_enum, default_value_t = LogLevel::Debug)]
+    #[arg(long = "log-level", value_enum, default_value_t = LogLevel::Debug, global = true)]
     pub log_level: LogLevel,
+
+    /// Run a non-interactive command instead of the TUI
+    #[command(subcommand)]
+    pub command: Option<Command>,
 }
 
 impl Cli {
@@ -59,3 +72,79 @@ impl Cli {
         self.log_file.is_some()
     }
 }
+
+/// Non-interactive subcommands. Without a subcommand the TUI is launched.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Check every artifact and generate the missing ones without the TUI
+    Generate(GenerateArgs),
+    /// Check every artifact without generating anything
+    ///
+    /// Exits with 0 when every artifact is present, 3 when at least one needs
+    /// generation and 4 when at least one check failed.
+    Status(StatusArgs),
+    /// Export the inventory of every declared artifact
+    List(ListArgs),
+}
+
+impl Command {
+    /// Flake directory given to the subcommand, if any.
+    pub fn flake(&self) -> Option<&PathBuf> {
+        match self {
+            Command::Generate(args) => args.flake.as_ref(),
+            Command::Status(args) => args.flake.as_ref(),
+            Command::List(args) => args.flake.as_ref(),
+        }
+    }
+}
+
+/// Output format for reports.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// One line per artifact
+    #[default]
+    Text,
+    /// JSON array
+    Json,
+}
+
+/// Output format for `artifacts list`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum ListFormat {
+    /// JSON document
+    #[default]
+    Json,
+    /// TOML document
+    Toml,
+    /// Markdown table
+    Markdown,
+}
+
+/// Arguments for `artifacts generate`.
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    /// Path to flake directory (default: current directory)
+    pub flake: Option<PathBuf>,
+}
+
+/// Arguments for `artifacts status`.
+#[derive(Debug, Args)]
+pub struct StatusArgs {
+    /// Path to flake directory (default: current directory)
+    pub flake: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+/// Arguments for `artifacts list`.
+#[derive(Debug, Args)]
+pub struct ListArgs {
+    /// Path to flake directory (default: current directory)
+    pub flake: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = ListFormat::Json)]
+    pub format: ListFormat,
+}
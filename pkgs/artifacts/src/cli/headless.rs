@@ -0,0 +1,492 @@
+//! Headless front end for the check / generate / serialize pipeline.
+//!
+//! The TUI runtime renders the [`Model`] and turns key presses into
+//! [`Message`]s. This module drives the very same pure [`update`] loop
+//! without a terminal: it feeds synthetic messages (the `a` generate-all key,
+//! prompt submissions) and executes every [`Effect`] inline through a
+//! [`BackgroundEffectHandler`]. Progress is printed one line per finished
+//! step so the output stays readable in CI logs and over plain SSH sessions.
+//!
+//! # Prompts
+//!
+//! Prompt values are read line by line from the given reader (stdin in
+//! production). When the reader is exhausted the artifact is skipped, exactly
+//! like pressing Esc on the inline prompt in the TUI.
+//!
+//! # Status
+//!
+//! [`run_status`] only executes the initial `CheckSerialization` effects and
+//! never prompts or generates; it backs the read-only `artifacts status`
+//! command.
+
+use crate::app::effect::Effect;
+use crate::app::message::{KeyEvent, Message, ScriptOutput};
+use crate::app::model::{ArtifactStatus, ListEntry, Model};
+use crate::app::{init, update};
+use crate::backend::generator::signal_kill_slot;
+use crate::tui::background::BackgroundEffectHandler;
+use crate::tui::runtime::effect_to_command;
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, Write};
+
+/// Human-readable label for an entry, e.g. `machine-one/ssh-key` or
+/// `shared/wifi-password`.
+pub fn entry_label(entry: &ListEntry) -> String {
+    match entry {
+        ListEntry::Single(single) => format!(
+            "{}/{}",
+            single.target_type.target_name(),
+            single.artifact.name
+        ),
+        ListEntry::Shared(shared) => format!("shared/{}", shared.info.artifact_name),
+    }
+}
+
+/// Check every entry and generate all that need it, without a TUI.
+///
+/// Equivalent to starting the TUI and pressing `a`: up-to-date entries are
+/// left alone, missing ones go through generate → serialize one at a time.
+/// Shared artifacts with more than one generator cannot be resolved without
+/// the generator-selection dialog and stay in `NeedsGeneration`.
+///
+/// Returns the final model so the caller can summarise the outcome.
+pub async fn run_generate<R, W>(
+    handler: &mut BackgroundEffectHandler,
+    mut model: Model,
+    input: &mut R,
+    out: &mut W,
+) -> Result<Model>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut pending: VecDeque<Effect> = effect_to_command(init(&mut model)).into();
+
+    // Queue every entry exactly like the `a` key does in the TUI. Pending
+    // entries are picked up as soon as their check resolves.
+    let (next, effect) = update(model, Message::Key(KeyEvent::char('a')));
+    model = next;
+    pending.extend(effect_to_command(effect));
+
+    loop {
+        if let Some(effect) = pending.pop_front() {
+            let (msg, interrupted) = execute(handler, effect).await;
+            let report = Report::for_message(&msg);
+            let (next, effect) = update(model, msg);
+            model = next;
+            if let Some(report) = report {
+                report.print(&model, out)?;
+            }
+            if interrupted {
+                bail!("Interrupted");
+            }
+            pending.extend(effect_to_command(effect));
+            continue;
+        }
+
+        if model.active_prompt.is_some() {
+            let (next, effect) = answer_prompt(model, input, out)?;
+            model = next;
+            pending.extend(effect_to_command(effect));
+            continue;
+        }
+
+        break;
+    }
+
+    Ok(model)
+}
+
+/// Check result of a single entry, as reported by `artifacts status`.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusEntry {
+    /// Machine or user name; `shared` for shared artifacts
+    pub target: String,
+    /// `nixos`, `homemanager` or `shared`
+    pub target_type: String,
+    pub artifact: String,
+    pub backend: String,
+    pub status: CheckState,
+    /// Error summary when `status` is `error`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub stdout: Vec<String>,
+    pub stderr: Vec<String>,
+}
+
+impl StatusEntry {
+    /// Same label format as [`entry_label`].
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.target, self.artifact)
+    }
+}
+
+/// Outcome of the check script for one entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CheckState {
+    UpToDate,
+    NeedsGeneration,
+    Error,
+}
+
+impl CheckState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckState::UpToDate => "up-to-date",
+            CheckState::NeedsGeneration => "needs-generation",
+            CheckState::Error => "error",
+        }
+    }
+}
+
+/// Run the check script of every entry and report the results.
+///
+/// Only the effects returned by [`init`] are executed. Check results never
+/// trigger a generator here because nothing is queued for generation, so this
+/// is safe to run as a pre-deploy gate.
+pub async fn run_status(
+    handler: &mut BackgroundEffectHandler,
+    mut model: Model,
+) -> Result<Vec<StatusEntry>> {
+    let mut outputs: HashMap<usize, ScriptOutput> = HashMap::new();
+
+    for effect in effect_to_command(init(&mut model)) {
+        let (msg, interrupted) = execute(handler, effect).await;
+        if interrupted {
+            bail!("Interrupted");
+        }
+        if let Message::CheckSerializationResult {
+            artifact_index,
+            result: Ok(output),
+            ..
+        } = &msg
+        {
+            outputs.insert(*artifact_index, output.clone());
+        }
+        // The generate queue is empty, so a check result never yields a
+        // follow-up effect worth running.
+        let (next, _) = update(model, msg);
+        model = next;
+    }
+
+    Ok(model
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| status_entry(entry, outputs.remove(&index).unwrap_or_default()))
+        .collect())
+}
+
+fn status_entry(entry: &ListEntry, output: ScriptOutput) -> StatusEntry {
+    let (status, error) = match entry.status() {
+        ArtifactStatus::UpToDate => (CheckState::UpToDate, None),
+        ArtifactStatus::Failed { error, .. } => (CheckState::Error, Some(error.summary())),
+        _ => (CheckState::NeedsGeneration, None),
+    };
+    let (target, target_type, backend) = match entry {
+        ListEntry::Single(single) => (
+            single.target_type.target_name().to_string(),
+            single.target_type.context_str().to_string(),
+            single.artifact.backend.clone(),
+        ),
+        ListEntry::Shared(shared) => (
+            "shared".to_string(),
+            "shared".to_string(),
+            shared.info.backend_name.clone(),
+        ),
+    };
+    StatusEntry {
+        target,
+        target_type,
+        artifact: entry.artifact_name().to_string(),
+        backend,
+        status,
+        error,
+        stdout: output.stdout_lines,
+        stderr: output.stderr_lines,
+    }
+}
+
+/// Execute one effect, killing an in-flight generator on Ctrl-C.
+///
+/// Returns the resulting message and whether the user interrupted the run.
+async fn execute(handler: &mut BackgroundEffectHandler, effect: Effect) -> (Message, bool) {
+    let kill_slot = handler.kill_slot_handle();
+    let exec = handler.execute(effect);
+    tokio::pin!(exec);
+
+    tokio::select! {
+        msg = &mut exec => (msg, false),
+        _ = tokio::signal::ctrl_c() => {
+            // Serialize and check run to completion; only a generator has a
+            // process group registered in the kill slot.
+            let _ = signal_kill_slot(&kill_slot);
+            (exec.await, true)
+        }
+    }
+}
+
+/// Collect the value for the current prompt of `model.active_prompt` and
+/// submit it through the regular prompt handler.
+fn answer_prompt<R, W>(mut model: Model, input: &mut R, out: &mut W) -> Result<(Model, Effect)>
+where
+    R: BufRead,
+    W: Write,
+{
+    let Some(state) = model.active_prompt.as_mut() else {
+        return Ok((model, Effect::None));
+    };
+    let Some(prompt) = state.current_prompt().cloned() else {
+        return Ok((model, Effect::None));
+    };
+    let label = entry_label(&model.entries[state.artifact_index]);
+
+    match &prompt.description {
+        Some(description) => eprint!("{} {} ({}): ", label, prompt.name, description),
+        None => eprint!("{} {}: ", label, prompt.name),
+    }
+
+    let mut line = String::new();
+    let read = input
+        .read_line(&mut line)
+        .context("Failed to read prompt value")?;
+
+    if read == 0 {
+        eprintln!();
+        writeln!(
+            out,
+            "skip      {}: no value for prompt '{}'",
+            label, prompt.name
+        )?;
+        // A second Esc inside the chord window would cancel the whole queue;
+        // each skip must stay a single Esc.
+        model.last_esc_at = None;
+        return Ok(update(model, Message::Key(KeyEvent::esc())));
+    }
+
+    let value = line.strip_suffix('\n').unwrap_or(&line);
+    state.buffer = value.strip_suffix('\r').unwrap_or(value).to_string();
+    Ok(update(model, Message::Key(KeyEvent::enter())))
+}
+
+/// One progress line describing a finished pipeline step.
+struct Report {
+    step: &'static str,
+    artifact_index: usize,
+}
+
+impl Report {
+    fn for_message(msg: &Message) -> Option<Self> {
+        let (step, artifact_index) = match msg {
+            Message::CheckSerializationResult { artifact_index, .. } => ("check", artifact_index),
+            Message::GeneratorFinished { artifact_index, .. }
+            | Message::GeneratorCancelled { artifact_index } => ("generate", artifact_index),
+            Message::SerializeFinished { artifact_index, .. } => ("serialize", artifact_index),
+            _ => return None,
+        };
+        Some(Self {
+            step,
+            artifact_index: *artifact_index,
+        })
+    }
+
+    fn print<W: Write>(&self, model: &Model, out: &mut W) -> Result<()> {
+        let Some(entry) = model.entries.get(self.artifact_index) else {
+            return Ok(());
+        };
+        let outcome = match entry.status() {
+            ArtifactStatus::UpToDate if self.step == "check" => "up to date".to_string(),
+            ArtifactStatus::NeedsGeneration => "needs generation".to_string(),
+            ArtifactStatus::Failed { error, .. } => format!("failed: {}", error.summary()),
+            ArtifactStatus::Cancelled { .. } => "cancelled".to_string(),
+            _ => "ok".to_string(),
+        };
+        writeln!(out, "{:<9} {}: {}", self.step, entry_label(entry), outcome)?;
+
+        if let ArtifactStatus::Failed { output, .. } = entry.status() {
+            for line in output.lines().filter(|l| !l.is_empty()) {
+                writeln!(out, "          {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::model::{ArtifactEntry, Screen, Step, TargetType};
+    use crate::config::backend::BackendConfiguration;
+    use crate::config::make::{ArtifactDef, MakeConfiguration, PromptDef};
+    use std::collections::{BTreeMap, HashMap};
+    use std::path::PathBuf;
+
+    fn make_artifact(name: &str, prompts: &[&str]) -> ArtifactDef {
+        ArtifactDef {
+            name: name.to_string(),
+            description: None,
+            shared: false,
+            files: BTreeMap::new(),
+            prompts: prompts
+                .iter()
+                .map(|p| {
+                    (
+                        p.to_string(),
+                        PromptDef {
+                            name: p.to_string(),
+                            description: None,
+                        },
+                    )
+                })
+                .collect(),
+            generator: "/gen".to_string(),
+            backend: "missing".to_string(),
+        }
+    }
+
+    fn make_model(status: ArtifactStatus, prompts: &[&str]) -> Model {
+        Model {
+            screen: Screen::ArtifactList,
+            entries: vec![ListEntry::Single(ArtifactEntry {
+                target_type: TargetType::NixOS {
+                    machine: "machine".to_string(),
+                },
+                artifact: make_artifact("ssh-key", prompts),
+                status,
+                runs: Vec::new(),
+            })],
+            selected_index: 0,
+            selected_log_step: Step::default(),
+            error: None,
+            warnings: Vec::new(),
+            tick_count: 0,
+            generate_queue: Default::default(),
+            active_prompt: None,
+            last_esc_at: None,
+            pipeline_queue: Default::default(),
+            in_flight: None,
+        }
+    }
+
+    fn make_handler() -> BackgroundEffectHandler {
+        let backend = BackendConfiguration {
+            config: HashMap::new(),
+            base_path: PathBuf::from("."),
+            backend_toml: PathBuf::from("./backend.toml"),
+        };
+        let make = MakeConfiguration {
+            nixos_map: BTreeMap::new(),
+            home_map: BTreeMap::new(),
+            nixos_config: BTreeMap::new(),
+            home_config: BTreeMap::new(),
+            make_base: PathBuf::from("."),
+            make_json: PathBuf::from("./make.json"),
+        };
+        BackgroundEffectHandler::new(backend, make, crate::logging::LogLevel::Info)
+    }
+
+    #[test]
+    fn test_entry_label() {
+        let model = make_model(ArtifactStatus::Pending, &[]);
+        assert_eq!(entry_label(&model.entries[0]), "machine/ssh-key");
+    }
+
+    #[tokio::test]
+    async fn test_run_generate_reports_failed_check() {
+        // The handler knows no artifacts, so the check fails and nothing is
+        // generated.
+        let mut handler = make_handler();
+        let mut input = std::io::empty();
+        let mut out = Vec::new();
+
+        let model = run_generate(
+            &mut handler,
+            make_model(ArtifactStatus::Pending, &[]),
+            &mut input,
+            &mut out,
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            model.entries[0].status(),
+            ArtifactStatus::Failed { .. }
+        ));
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.starts_with("check     machine/ssh-key: failed:"),
+            "{out}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_status_never_generates() {
+        let mut handler = make_handler();
+
+        let entries = run_status(&mut handler, make_model(ArtifactStatus::Pending, &[]))
+            .await
+            .unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].target, "machine");
+        assert_eq!(entries[0].target_type, "nixos");
+        assert_eq!(entries[0].backend, "missing");
+        assert_eq!(entries[0].status, CheckState::Error);
+        assert!(entries[0].error.is_some());
+    }
+
+    #[test]
+    fn test_status_entry_serializes_kebab_case() {
+        let model = make_model(ArtifactStatus::NeedsGeneration, &[]);
+        let entry = status_entry(
+            &model.entries[0],
+            ScriptOutput::from_message("missing file"),
+        );
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["status"], "needs-generation");
+        assert_eq!(json["stdout"][0], "missing file");
+        assert!(json.get("error").is_none());
+    }
+
+    #[test]
+    fn test_answer_prompt_submits_line() {
+        let mut model = make_model(ArtifactStatus::NeedsGeneration, &["passphrase"]);
+        model.generate_queue.insert(0);
+        crate::app::update::set_next_active_prompt(&mut model);
+        let mut input = "hunter2\n".as_bytes();
+        let mut out = Vec::new();
+
+        let (model, effect) = answer_prompt(model, &mut input, &mut out).unwrap();
+
+        assert!(model.active_prompt.is_none());
+        match effect {
+            Effect::RunGenerator { prompts, .. } => {
+                assert_eq!(
+                    prompts.get("passphrase").map(String::as_str),
+                    Some("hunter2")
+                );
+            }
+            other => panic!("Expected RunGenerator, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_answer_prompt_skips_on_eof() {
+        let mut model = make_model(ArtifactStatus::NeedsGeneration, &["passphrase"]);
+        model.generate_queue.insert(0);
+        crate::app::update::set_next_active_prompt(&mut model);
+        let mut input = std::io::empty();
+        let mut out = Vec::new();
+
+        let (model, effect) = answer_prompt(model, &mut input, &mut out).unwrap();
+
+        assert!(model.active_prompt.is_none());
+        assert!(model.generate_queue.is_empty());
+        assert!(effect.is_none());
+        assert_eq!(model.entries[0].status(), &ArtifactStatus::NeedsGeneration);
+    }
+}
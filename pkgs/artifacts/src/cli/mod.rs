@@ -2,7 +2,7 @@
 //!
 //! This module handles CLI argument parsing, path resolution, and TUI initialization.
 //! It serves as the bridge between the command-line interface and the interactive
-//! TUI application.
+//! TUI application or one of the headless subcommands.
 //!
 //! # CLI Flow
 //!
@@ -10,7 +10,16 @@
 //! 2. Resolve flake directory path (default: current directory)
 //! 3. Resolve backend.toml path (env var or flake directory)
 //! 4. Build make configuration from Nix flake evaluation
-//! 5. Initialize terminal and run TUI
+//! 5. Initialize terminal and run TUI, or run the requested subcommand
+//!
+//! # Subcommands
+//!
+//! - `generate` - Check every artifact and generate the missing ones without
+//!   a terminal UI (see [`headless`])
+//! - `status` - Run only the check scripts and report, as text or JSON,
+//!   which artifacts are present
+//! - `list` - Export the inventory of every declared artifact as JSON, TOML
+//!   or a Markdown table (does not need `backend.toml`)
 //!
 //! # Environment Variables
 //!
@@ -21,22 +30,37 @@
 //! - `0` - The TUI ran to completion. Per-artifact failures are summarised on
 //!   stderr but do not change the exit code.
 //! - `1` - The CLI itself failed (e.g. backend config load, nix evaluation,
-//!   terminal init, async runtime error).
+//!   terminal init, async runtime error). `artifacts generate` also exits
+//!   with `1` when any artifact failed or was left ungenerated.
+//! - `3` - `artifacts status`: at least one artifact needs generation.
+//! - `4` - `artifacts status`: at least one check failed.
 
 pub mod args;
+pub mod headless;
 
-use crate::app::model::{ArtifactStatus, ListEntry};
+use crate::app::model::ArtifactStatus;
+use crate::app::model::Model;
 use crate::config::backend::BackendConfiguration;
+use crate::config::inventory::Inventory;
 use crate::config::make::MakeConfiguration;
 use crate::config::nix::build_make_from_flake;
 use crate::log_info;
+use crate::tui::background::BackgroundEffectHandler;
 use crate::tui::{
     TerminalEventSource, TerminalGuard, build_model, install_panic_hook, run_async,
     validate_model_capabilities,
 };
 use anyhow::{Context, Result};
+use args::{Command, ListFormat, OutputFormat};
 use clap::Parser;
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Exit code of `artifacts status` when at least one artifact needs generation.
+pub const EXIT_NEEDS_GENERATION: u8 = 3;
+
+/// Exit code of `artifacts status` when at least one check failed.
+pub const EXIT_CHECK_ERROR: u8 = 4;
 
 /// Resolve the flake directory path.
 ///
@@ -93,7 +117,7 @@ fn resolve_backend_toml(flake_path: &Path) -> Result<PathBuf> {
     }
 }
 
-pub async fn run() -> Result<()> {
+pub async fn run() -> Result<ExitCode> {
     let cli = args::Cli::parse();
 
     // Initialize logger first using new macro-based system
@@ -109,17 +133,62 @@ pub async fn run() -> Result<()> {
     }
 
     // Resolve paths
-    let flake_path = resolve_flake_path(&cli.flake);
-    let backend_path = resolve_backend_toml(&flake_path)?;
-    let make_path = build_make_from_flake(&flake_path)?;
+    let flake = cli
+        .command
+        .as_ref()
+        .and_then(Command::flake)
+        .cloned()
+        .or(cli.flake);
+    let flake_path = resolve_flake_path(&flake);
+
+    match cli.command {
+        Some(Command::Generate(_)) => {
+            let (backend_path, make_path) = resolve_configuration_paths(&flake_path)?;
+            run_generate(&backend_path, &make_path).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Status(status)) => {
+            let (backend_path, make_path) = resolve_configuration_paths(&flake_path)?;
+            run_status(&backend_path, &make_path, status.format).await
+        }
+        // The inventory is derived from the flake alone; no backend.toml needed.
+        Some(Command::List(list)) => {
+            run_list(&build_make_from_flake(&flake_path)?, list.format)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        None => {
+            let (backend_path, make_path) = resolve_configuration_paths(&flake_path)?;
+            run_tui(&backend_path, &make_path).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
 
-    // Run TUI
-    run_tui(&backend_path, &make_path).await
+/// Resolve backend.toml and evaluate the flake into make.json.
+fn resolve_configuration_paths(flake_path: &Path) -> Result<(PathBuf, PathBuf)> {
+    let backend_path = resolve_backend_toml(flake_path)?;
+    let make_path = build_make_from_flake(flake_path)?;
+    Ok((backend_path, make_path))
 }
 
-async fn run_tui(backend_path: &Path, make_path: &Path) -> Result<()> {
-    // STEP 1: Load all configurations BEFORE terminal setup (ERR-01)
-    // Errors here print to stderr and exit non-zero
+fn run_list(make_path: &Path, format: ListFormat) -> Result<()> {
+    let make = MakeConfiguration::read_make_config(make_path)
+        .with_context(|| "Failed to load artifact definitions from nix evaluation".to_string())?;
+    let inventory = Inventory::from_make(&make);
+
+    match format {
+        ListFormat::Json => println!("{}", inventory.to_json()?),
+        ListFormat::Toml => print!("{}", inventory.to_toml()?),
+        ListFormat::Markdown => print!("{}", inventory.to_markdown()),
+    }
+    Ok(())
+}
+
+/// Load both configurations from disk.
+fn load_configurations(
+    backend_path: &Path,
+    make_path: &Path,
+) -> Result<(BackendConfiguration, MakeConfiguration)> {
     let backend = BackendConfiguration::read_backend_config(backend_path).with_context(|| {
         format!(
             "Failed to load backend configuration from '{}'",
@@ -130,6 +199,129 @@ async fn run_tui(backend_path: &Path, make_path: &Path) -> Result<()> {
     let make = MakeConfiguration::read_make_config(make_path)
         .with_context(|| "Failed to load artifact definitions from nix evaluation".to_string())?;
 
+    Ok((backend, make))
+}
+
+/// Summaries of every entry that ended in [`ArtifactStatus::Failed`].
+fn failed_summaries(model: &Model) -> Vec<String> {
+    model
+        .entries
+        .iter()
+        .filter_map(|entry| match entry.status() {
+            ArtifactStatus::Failed { error, .. } => Some(format!(
+                "{}: {}",
+                headless::entry_label(entry),
+                error.summary()
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+async fn run_generate(backend_path: &Path, make_path: &Path) -> Result<()> {
+    let (backend, make) = load_configurations(backend_path, make_path)?;
+
+    let mut model = build_model(&make);
+    validate_model_capabilities(&mut model, &backend);
+    for warning in &model.warnings {
+        eprintln!("warning: {}: {}", warning.artifact_name, warning.message);
+    }
+
+    if model.entries.is_empty() {
+        log_info!("No artifacts found");
+        println!("No artifacts found.");
+        return Ok(());
+    }
+
+    log_info!(
+        "Starting headless generate with {} entries",
+        model.entries.len()
+    );
+
+    let mut handler = BackgroundEffectHandler::new(backend, make, crate::logging::current_level());
+    let stdin = std::io::stdin();
+    let model = headless::run_generate(
+        &mut handler,
+        model,
+        &mut stdin.lock(),
+        &mut std::io::stdout(),
+    )
+    .await?;
+
+    let failed = failed_summaries(&model);
+    if !failed.is_empty() {
+        eprintln!("Failed artifacts:");
+        for msg in &failed {
+            eprintln!("  {}", msg);
+        }
+    }
+
+    let not_generated: Vec<String> = model
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.status(), ArtifactStatus::NeedsGeneration))
+        .map(headless::entry_label)
+        .collect();
+    if !not_generated.is_empty() {
+        eprintln!("Not generated:");
+        for label in &not_generated {
+            eprintln!("  {}", label);
+        }
+    }
+
+    if !failed.is_empty() || !not_generated.is_empty() {
+        anyhow::bail!(
+            "{} artifact(s) failed, {} not generated",
+            failed.len(),
+            not_generated.len()
+        );
+    }
+    Ok(())
+}
+
+async fn run_status(
+    backend_path: &Path,
+    make_path: &Path,
+    format: OutputFormat,
+) -> Result<ExitCode> {
+    let (backend, make) = load_configurations(backend_path, make_path)?;
+
+    let mut model = build_model(&make);
+    validate_model_capabilities(&mut model, &backend);
+
+    let mut handler = BackgroundEffectHandler::new(backend, make, crate::logging::current_level());
+    let entries = headless::run_status(&mut handler, model).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&entries)?);
+        }
+        OutputFormat::Text => {
+            for entry in &entries {
+                let label = entry.label();
+                match &entry.error {
+                    Some(error) => println!("{:<16} {}: {}", entry.status.as_str(), label, error),
+                    None => println!("{:<16} {}", entry.status.as_str(), label),
+                }
+            }
+        }
+    }
+
+    let has = |state| entries.iter().any(|e| e.status == state);
+    if has(headless::CheckState::Error) {
+        Ok(ExitCode::from(EXIT_CHECK_ERROR))
+    } else if has(headless::CheckState::NeedsGeneration) {
+        Ok(ExitCode::from(EXIT_NEEDS_GENERATION))
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+async fn run_tui(backend_path: &Path, make_path: &Path) -> Result<()> {
+    // STEP 1: Load all configurations BEFORE terminal setup (ERR-01)
+    // Errors here print to stderr and exit non-zero
+    let (backend, make) = load_configurations(backend_path, make_path)?;
+
     // Build the initial model
     let mut model = build_model(&make);
 
@@ -165,33 +357,7 @@ async fn run_tui(backend_path: &Path, make_path: &Path) -> Result<()> {
 
     match result {
         Ok(run_result) => {
-            let failed: Vec<_> = run_result
-                .final_model
-                .entries
-                .iter()
-                .filter_map(|entry| match entry {
-                    ListEntry::Single(a) => match &a.status {
-                        ArtifactStatus::Failed { error, .. } => {
-                            let target = a.target_type.target_name();
-                            Some(format!(
-                                "{}/{}: {}",
-                                target,
-                                a.artifact.name,
-                                error.summary()
-                            ))
-                        }
-                        _ => None,
-                    },
-                    ListEntry::Shared(s) => match &s.status {
-                        ArtifactStatus::Failed { error, .. } => Some(format!(
-                            "shared/{}: {}",
-                            s.info.artifact_name,
-                            error.summary()
-                        )),
-                        _ => None,
-                    },
-                })
-                .collect();
+            let failed = failed_summaries(&run_result.final_model);
 
             if !failed.is_empty() {
                 eprintln!("Failed artifacts:");
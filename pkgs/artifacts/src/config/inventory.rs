@@ -0,0 +1,275 @@
+//! Machine-readable inventory of every declared artifact.
+//!
+//! The inventory is a flat, serializable view of a [`MakeConfiguration`]:
+//! one record per per-target artifact plus one aggregated
+//! [`SharedArtifactInfo`] per shared artifact, including file-mismatch
+//! validation errors. It backs the `artifacts list` command and can be
+//! rendered as JSON, TOML or a Markdown table.
+
+use super::make::{ArtifactDef, FileDef, MakeConfiguration, SharedArtifactInfo, TargetType};
+use anyhow::Context;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Every artifact declared in the flake.
+#[derive(Debug, Clone, Serialize)]
+pub struct Inventory {
+    /// Per-target artifacts (shared artifacts are listed in `shared` instead)
+    pub artifacts: Vec<InventoryArtifact>,
+    /// Shared artifacts, aggregated across all targets that declare them
+    pub shared: Vec<SharedArtifactInfo>,
+}
+
+/// A per-target artifact together with the machine or user declaring it.
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryArtifact {
+    /// Machine name or user identifier
+    pub target: String,
+    pub target_type: TargetType,
+    #[serde(flatten)]
+    pub artifact: ArtifactDef,
+}
+
+impl Inventory {
+    /// Build the inventory from a parsed make configuration.
+    pub fn from_make(make: &MakeConfiguration) -> Self {
+        let shared = make.get_shared_artifacts();
+
+        let per_target = |map: &BTreeMap<String, BTreeMap<String, ArtifactDef>>,
+                          target_type: TargetType| {
+            map.iter()
+                .flat_map(|(target, artifacts)| {
+                    artifacts
+                        .values()
+                        .filter(|artifact| !shared.contains_key(&artifact.name))
+                        .map(|artifact| InventoryArtifact {
+                            target: target.clone(),
+                            target_type: target_type.clone(),
+                            artifact: artifact.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut artifacts = per_target(&make.nixos_map, TargetType::Nixos);
+        artifacts.extend(per_target(&make.home_map, TargetType::HomeManager));
+
+        Self {
+            artifacts,
+            shared: shared.into_values().collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize inventory as JSON")
+    }
+
+    pub fn to_toml(&self) -> anyhow::Result<String> {
+        toml::to_string_pretty(self).context("Failed to serialize inventory as TOML")
+    }
+
+    /// Render a Markdown table with one row per artifact, followed by a list
+    /// of shared-artifact validation errors (if any).
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        out.push_str("| Target | Artifact | Backend | Files | Prompts | Generator |\n");
+        out.push_str("| --- | --- | --- | --- | --- | --- |\n");
+
+        for entry in &self.artifacts {
+            let target = match entry.target_type {
+                TargetType::Nixos => format!("nixos: {}", entry.target),
+                TargetType::HomeManager => format!("home: {}", entry.target),
+            };
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {} |",
+                escape_cell(&target),
+                escape_cell(&entry.artifact.name),
+                escape_cell(&entry.artifact.backend),
+                files_cell(&entry.artifact.files),
+                escape_cell(&prompts_cell(entry.artifact.prompts.keys())),
+                escape_cell(&entry.artifact.generator),
+            );
+        }
+
+        for shared in &self.shared {
+            let targets: Vec<String> = shared
+                .nixos_targets
+                .iter()
+                .map(|t| format!("nixos: {}", t))
+                .chain(shared.home_targets.iter().map(|t| format!("home: {}", t)))
+                .collect();
+            let generators: Vec<&str> = shared.generators.iter().map(|g| g.path.as_str()).collect();
+            let _ = writeln!(
+                out,
+                "| shared ({}) | {} | {} | {} | {} | {} |",
+                escape_cell(&targets.join(", ")),
+                escape_cell(&shared.artifact_name),
+                escape_cell(&shared.backend_name),
+                files_cell(&shared.files),
+                escape_cell(&prompts_cell(shared.prompts.keys())),
+                escape_cell(&generators.join(", ")),
+            );
+        }
+
+        let errors: Vec<&SharedArtifactInfo> =
+            self.shared.iter().filter(|s| s.error.is_some()).collect();
+        if !errors.is_empty() {
+            out.push_str("\n## Validation errors\n\n");
+            for shared in errors {
+                let _ = writeln!(
+                    out,
+                    "- `{}`: {}",
+                    shared.artifact_name,
+                    shared.error.as_deref().unwrap_or_default()
+                );
+            }
+        }
+
+        out
+    }
+}
+
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn prompts_cell<'a>(names: impl Iterator<Item = &'a String>) -> String {
+    names.map(String::as_str).collect::<Vec<_>>().join(", ")
+}
+
+/// `name → path (owner:group)` per file, separated by `<br>`.
+fn files_cell(files: &BTreeMap<String, FileDef>) -> String {
+    files
+        .values()
+        .map(|file| {
+            let mut cell = escape_cell(&file.name);
+            if let Some(path) = &file.path {
+                let _ = write!(cell, " → {}", escape_cell(path));
+            }
+            if file.owner.is_some() || file.group.is_some() {
+                let _ = write!(
+                    cell,
+                    " ({}:{})",
+                    escape_cell(file.owner.as_deref().unwrap_or("-")),
+                    escape_cell(file.group.as_deref().unwrap_or("-"))
+                );
+            }
+            cell
+        })
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn make_artifact(name: &str, shared: bool, files: &[&str]) -> ArtifactDef {
+        ArtifactDef {
+            name: name.to_string(),
+            description: None,
+            shared,
+            files: files
+                .iter()
+                .map(|f| {
+                    (
+                        f.to_string(),
+                        FileDef {
+                            name: f.to_string(),
+                            path: Some(format!("/run/secrets/{}", f)),
+                            owner: Some("root".to_string()),
+                            group: None,
+                        },
+                    )
+                })
+                .collect(),
+            prompts: BTreeMap::new(),
+            generator: format!("/gen/{}", name),
+            backend: "test".to_string(),
+        }
+    }
+
+    fn make_config() -> MakeConfiguration {
+        let machine_one = BTreeMap::from([
+            (
+                "ssh-key".to_string(),
+                make_artifact("ssh-key", false, &["key"]),
+            ),
+            ("shared".to_string(), make_artifact("shared", true, &["a"])),
+        ]);
+        let machine_two = BTreeMap::from([(
+            "shared".to_string(),
+            make_artifact("shared", true, &["a", "b"]),
+        )]);
+        let alice = BTreeMap::from([("gpg".to_string(), make_artifact("gpg", false, &[]))]);
+
+        MakeConfiguration {
+            nixos_map: BTreeMap::from([
+                ("machine-one".to_string(), machine_one),
+                ("machine-two".to_string(), machine_two),
+            ]),
+            home_map: BTreeMap::from([("alice".to_string(), alice)]),
+            nixos_config: BTreeMap::new(),
+            home_config: BTreeMap::new(),
+            make_base: PathBuf::from("/test"),
+            make_json: PathBuf::from("/test/make.json"),
+        }
+    }
+
+    #[test]
+    fn test_inventory_separates_shared_artifacts() {
+        let inventory = Inventory::from_make(&make_config());
+
+        let names: Vec<(&str, &str)> = inventory
+            .artifacts
+            .iter()
+            .map(|a| (a.target.as_str(), a.artifact.name.as_str()))
+            .collect();
+        assert_eq!(names, vec![("machine-one", "ssh-key"), ("alice", "gpg")]);
+
+        assert_eq!(inventory.shared.len(), 1);
+        assert_eq!(
+            inventory.shared[0].nixos_targets,
+            vec!["machine-one", "machine-two"]
+        );
+        assert!(inventory.shared[0].error.is_some());
+    }
+
+    #[test]
+    fn test_inventory_json_flattens_artifact() {
+        let inventory = Inventory::from_make(&make_config());
+        let json: serde_json::Value = serde_json::from_str(&inventory.to_json().unwrap()).unwrap();
+
+        let first = &json["artifacts"][0];
+        assert_eq!(first["target"], "machine-one");
+        assert_eq!(first["target_type"], "Nixos");
+        assert_eq!(first["name"], "ssh-key");
+        assert_eq!(first["files"]["key"]["path"], "/run/secrets/key");
+        assert!(json["shared"][0]["error"].is_string());
+    }
+
+    #[test]
+    fn test_inventory_toml_round_trips() {
+        let inventory = Inventory::from_make(&make_config());
+        let value: toml::Value = toml::from_str(&inventory.to_toml().unwrap()).unwrap();
+
+        assert_eq!(value["artifacts"][1]["target"].as_str(), Some("alice"));
+        assert_eq!(value["shared"][0]["backend_name"].as_str(), Some("test"));
+    }
+
+    #[test]
+    fn test_inventory_markdown() {
+        let inventory = Inventory::from_make(&make_config());
+        let markdown = inventory.to_markdown();
+
+        assert!(markdown.contains(
+            "| nixos: machine-one | ssh-key | test | key → /run/secrets/key (root:-) |  | /gen/ssh-key |"
+        ));
+        assert!(markdown.contains("| shared (nixos: machine-one, nixos: machine-two) | shared |"));
+        assert!(markdown.contains("## Validation errors"));
+    }
+}
@@ -22,9 +22,11 @@
 //! ## Module Structure
 //!
 //! - [`backend`] - Backend configuration parsing from TOML
+//! - [`inventory`] - Serializable inventory of all declared artifacts
 //! - [`make`] - Artifact definitions from Nix flake evaluation
 //! - [`nix`] - Nix expression building and evaluation helpers
 
 pub mod backend;
+pub mod inventory;
 pub mod make;
 pub mod nix;
@@ -8,7 +8,8 @@
 //!
 //! The CLI launches an interactive terminal UI that drives the full
 //! check / generate / serialize lifecycle for every artifact declared in the
-//! flake. It is the only mode of operation; there are no subcommands.
+//! flake. The `generate` subcommand drives the same lifecycle headless, for
+//! scripts, CI jobs and sessions without a proper TTY.
 //!
 //! ## Architecture
 //!
@@ -50,6 +51,9 @@
 //! # Point the TUI at a different flake directory
 //! artifacts /path/to/flake
 //!
+//! # Generate every missing artifact without the TUI
+//! artifacts generate /path/to/flake
+//!
 //! # Enable file logging for debugging
 //! artifacts --log-file /tmp/artifacts.log --log-level debug
 //! ```
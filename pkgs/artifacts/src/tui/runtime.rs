@@ -404,11 +404,7 @@ pub fn effect_to_command(effect: Effect) -> Vec<Effect> {
 /// Returns the final model state after all events are processed.
 /// This is useful for testing state transitions without rendering.
 pub fn simulate<E: EventSource>(events: &mut E, mut model: Model) -> Model {
-    loop {
-        let Some(msg) = events.next_event() else {
-            break;
-        };
-
+    while let Some(msg) = events.next_event() {
         let (new_model, effect) = update(model, msg);
         model = new_model;
 
@@ -426,11 +422,7 @@ pub fn simulate_with_history<E: EventSource>(events: &mut E, initial: Model) ->
     let mut history = vec![initial.clone()];
     let mut model = initial;
 
-    loop {
-        let Some(msg) = events.next_event() else {
-            break;
-        };
-
+    while let Some(msg) = events.next_event() {
         let (new_model, effect) = update(model, msg);
         model = new_model.clone();
         history.push(new_model);
@@ -54,3 +54,31 @@ fn cli_with_log_level() {
         assert_cmd_snapshot!(cli().args(["--log-level", "debug"]));
     });
 }
+
+/// `generate` subcommand help output.
+#[test]
+#[serial]
+fn cli_generate_help() {
+    assert_cmd_snapshot!(cli().args(["generate", "--help"]));
+}
+
+/// `generate` with an invalid flake path shows the same error as the TUI.
+#[test]
+#[serial]
+fn cli_generate_invalid_flake_path() {
+    assert_cmd_snapshot!(cli().args(["generate", "/nonexistent/path"]));
+}
+
+/// `status` subcommand help output, including the exit code contract.
+#[test]
+#[serial]
+fn cli_status_help() {
+    assert_cmd_snapshot!(cli().args(["status", "--help"]));
+}
+
+/// `status` rejects unknown output formats.
+#[test]
+#[serial]
+fn cli_status_invalid_format() {
+    assert_cmd_snapshot!(cli().args(["status", "--format", "yaml"]));
+}
@@ -429,7 +429,7 @@ impl TestHarness {
             }
         }
 
-        for (prompt_name, _value) in prompts {
+        for prompt_name in prompts.keys() {
             diagnostics
                 .temp_prompt_contents
                 .insert(prompt_name.clone(), "[REDACTED]".to_string());
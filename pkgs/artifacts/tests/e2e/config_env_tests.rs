@@ -66,7 +66,7 @@ fn find_shared_artifact(
     make_config: &artifacts::config::make::MakeConfiguration,
     artifact_name: &str,
 ) -> Option<(String, artifacts::config::make::ArtifactDef)> {
-    for (_machine, artifacts) in &make_config.nixos_map {
+    for artifacts in make_config.nixos_map.values() {
         for (name, def) in artifacts {
             if name == artifact_name && def.shared {
                 let mut def_clone = def.clone();